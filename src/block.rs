@@ -0,0 +1,130 @@
+use crate::Template;
+
+/// A named slot a base template reserves for a `#[block]` that a derived
+/// template may override. A base's `create`/`define` body builds its default
+/// blocks as `Block::new(default)`; code composing a derived template over
+/// it calls [`Block::override_with`] before the base's `Output` is handed
+/// back, and [`Block::into_inner`] extracts the resolved sub-`Output` to
+/// place in the slot.
+pub struct Block<O> {
+    value: O,
+}
+
+impl<O> Block<O> {
+    pub fn new(default: O) -> Self {
+        Block { value: default }
+    }
+
+    pub fn override_with(&mut self, value: O) {
+        self.value = value;
+    }
+
+    pub fn get(&self) -> &O {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> O {
+        self.value
+    }
+}
+
+/// A base template with two overridable blocks, `header` and `body`, used
+/// below to work the composition algorithm end-to-end by hand: the
+/// `#[template(base = ...)]` / `#[block]` attributes that would generate a
+/// `define` like this one for an arbitrary user struct live in the
+/// `prelude` proc-macro crate, which this tree does not include, so this
+/// stands in as the worked example the macro would otherwise produce.
+pub struct BasePage;
+
+pub struct PageOutput {
+    pub header: Block<String>,
+    pub body: Block<String>,
+}
+
+impl Template for BasePage {
+    type Output = PageOutput;
+
+    fn define(self) -> PageOutput {
+        PageOutput {
+            header: Block::new("Default header".to_string()),
+            body: Block::new("Default body".to_string()),
+        }
+    }
+}
+
+/// A derived template overriding zero or more of [`BasePage`]'s blocks.
+/// Composition order matches the request: the base runs first, producing
+/// its default blocks, and only then are any overrides substituted into the
+/// slots the base reserved for them.
+#[derive(Default)]
+pub struct DerivedPage {
+    pub header: Option<String>,
+    pub body: Option<String>,
+}
+
+impl Template for DerivedPage {
+    type Output = PageOutput;
+
+    fn define(self) -> PageOutput {
+        let mut output = BasePage.define();
+        if let Some(header) = self.header {
+            output.header.override_with(header);
+        }
+        if let Some(body) = self.body {
+            output.body.override_with(body);
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn override_replaces_default() {
+        let mut block = Block::new("base");
+        assert_eq!(*block.get(), "base");
+
+        block.override_with("derived");
+        assert_eq!(block.into_inner(), "derived");
+    }
+
+    #[test]
+    fn unoverridden_block_keeps_default() {
+        let block = Block::new(42);
+        assert_eq!(block.into_inner(), 42);
+    }
+
+    #[test]
+    fn derived_with_no_overrides_keeps_every_base_default() {
+        let output = DerivedPage::default().define();
+
+        assert_eq!(output.header.into_inner(), "Default header");
+        assert_eq!(output.body.into_inner(), "Default body");
+    }
+
+    #[test]
+    fn derived_overriding_one_block_leaves_the_other_at_its_base_default() {
+        let output = DerivedPage {
+            header: Some("Custom header".to_string()),
+            ..Default::default()
+        }
+        .define();
+
+        assert_eq!(output.header.into_inner(), "Custom header");
+        assert_eq!(output.body.into_inner(), "Default body");
+    }
+
+    #[test]
+    fn derived_overriding_every_block_substitutes_them_all() {
+        let output = DerivedPage {
+            header: Some("Custom header".to_string()),
+            body: Some("Custom body".to_string()),
+        }
+        .define();
+
+        assert_eq!(output.header.into_inner(), "Custom header");
+        assert_eq!(output.body.into_inner(), "Custom body");
+    }
+}
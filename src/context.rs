@@ -0,0 +1,173 @@
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+thread_local! {
+    static CONTEXT_STACK: RefCell<Vec<HashMap<TypeId, Rc<dyn Any>>>> = RefCell::new(Vec::new());
+}
+
+/// Pushes a fresh, empty context frame on construction and pops it on drop
+/// (including on unwind), so nested `build`/`create` calls see correctly
+/// scoped context regardless of early return or panic.
+pub struct ContextScope {
+    _private: (),
+}
+
+impl ContextScope {
+    pub fn enter() -> Self {
+        CONTEXT_STACK.with(|stack| stack.borrow_mut().push(HashMap::new()));
+        ContextScope { _private: () }
+    }
+}
+
+impl Drop for ContextScope {
+    fn drop(&mut self) {
+        CONTEXT_STACK.with(|stack| {
+            stack.borrow_mut().pop();
+        });
+    }
+}
+
+/// Publishes `value` for descendants of the current [`ContextScope`] to read
+/// via [`use_context`]. Providing another `T` in an inner scope shadows this
+/// one for the lifetime of that inner scope.
+pub fn provide_context<T: 'static>(value: T) {
+    CONTEXT_STACK.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let frame = stack
+            .last_mut()
+            .expect("provide_context called outside a ContextScope");
+        frame.insert(TypeId::of::<T>(), Rc::new(value) as Rc<dyn Any>);
+    });
+}
+
+/// Reads the nearest `T` published by [`provide_context`], searching from
+/// the innermost scope outward. Panics if no enclosing scope provided one;
+/// use [`try_use_context`] where a missing provider is expected.
+pub fn use_context<T: 'static>() -> Rc<T> {
+    try_use_context::<T>()
+        .unwrap_or_else(|| panic!("no context of type {} provided in scope", std::any::type_name::<T>()))
+}
+
+/// Non-panicking form of [`use_context`].
+pub fn try_use_context<T: 'static>() -> Option<Rc<T>> {
+    CONTEXT_STACK.with(|stack| {
+        let stack = stack.borrow();
+        stack
+            .iter()
+            .rev()
+            .find_map(|frame| frame.get(&TypeId::of::<T>()).cloned())
+            .and_then(|value| value.downcast::<T>().ok())
+    })
+}
+
+/// A point-in-time copy of the enclosing context stack. `on_create` bodies
+/// run inside a [`crate::create_effect`] and may be re-invoked by a later,
+/// unrelated `Signal::set` long after the `build()` call that registered
+/// them returned and popped its [`ContextScope`]. Such a body must call
+/// [`capture_context`] while its `ContextScope` is still active, then wrap
+/// every (re)run in [`ContextSnapshot::with`] so `use_context` keeps seeing
+/// the context as of registration, regardless of what else is on the
+/// thread-local stack when the rerun actually happens.
+#[derive(Clone)]
+pub struct ContextSnapshot {
+    frames: Vec<HashMap<TypeId, Rc<dyn Any>>>,
+}
+
+pub fn capture_context() -> ContextSnapshot {
+    CONTEXT_STACK.with(|stack| ContextSnapshot {
+        frames: stack.borrow().clone(),
+    })
+}
+
+impl ContextSnapshot {
+    /// Runs `f` with this snapshot as the active context stack, then
+    /// restores whatever was active beforehand, even if `f` panics.
+    pub fn with<R>(&self, f: impl FnOnce() -> R) -> R {
+        let previous = CONTEXT_STACK.with(|stack| stack.replace(self.frames.clone()));
+        let _restore = RestorePrevious(Some(previous));
+        f()
+    }
+}
+
+struct RestorePrevious(Option<Vec<HashMap<TypeId, Rc<dyn Any>>>>);
+
+impl Drop for RestorePrevious {
+    fn drop(&mut self) {
+        if let Some(previous) = self.0.take() {
+            CONTEXT_STACK.with(|stack| *stack.borrow_mut() = previous);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_value_from_enclosing_scope() {
+        let _scope = ContextScope::enter();
+        provide_context(42i32);
+
+        assert_eq!(*use_context::<i32>(), 42);
+    }
+
+    #[test]
+    fn inner_provider_shadows_outer_for_its_scope_only() {
+        let outer = ContextScope::enter();
+        provide_context("outer");
+
+        {
+            let _inner = ContextScope::enter();
+            provide_context("inner");
+            assert_eq!(*use_context::<&str>(), "inner");
+        }
+
+        assert_eq!(*use_context::<&str>(), "outer");
+        drop(outer);
+    }
+
+    #[test]
+    fn scope_is_popped_on_drop() {
+        {
+            let _scope = ContextScope::enter();
+            provide_context(1u8);
+        }
+
+        assert!(try_use_context::<u8>().is_none());
+    }
+
+    #[test]
+    fn scope_is_popped_on_panic() {
+        let result = std::panic::catch_unwind(|| {
+            let _scope = ContextScope::enter();
+            provide_context(2u16);
+            panic!("boom");
+        });
+        assert!(result.is_err());
+
+        assert!(try_use_context::<u16>().is_none());
+    }
+
+    #[test]
+    fn snapshot_survives_its_originating_scope_closing() {
+        let snapshot = {
+            let _scope = ContextScope::enter();
+            provide_context("registration-time value");
+            capture_context()
+        };
+        // The scope above is gone; nothing is on the stack right now.
+        assert!(try_use_context::<&str>().is_none());
+
+        // An unrelated scope is active when the rerun actually happens.
+        let _unrelated = ContextScope::enter();
+        provide_context("unrelated value");
+
+        let seen = snapshot.with(|| *use_context::<&str>());
+        assert_eq!(seen, "registration-time value");
+
+        // The unrelated scope's own context is restored afterward.
+        assert_eq!(*use_context::<&str>(), "unrelated value");
+    }
+}
@@ -0,0 +1,214 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::{create_effect, EffectHandle, Signal, Template};
+
+/// The `Output` of a [`ForEach`] template: a keyed, ordered list of child
+/// outputs that can be reconciled against a new source without rebuilding
+/// untouched entries.
+///
+/// A `TemplateList` holds the [`EffectHandle`] that keeps its reconciliation
+/// live, so the effect (and the source `Signal` clone it captured) is torn
+/// down exactly when the last clone of this list is dropped — not before,
+/// and not kept alive forever by an `Rc` cycle through the `Signal`.
+pub struct TemplateList<K, O> {
+    entries: Rc<RefCell<Vec<(K, O)>>>,
+    effect: Rc<RefCell<Option<EffectHandle>>>,
+}
+
+impl<K, O> Clone for TemplateList<K, O> {
+    fn clone(&self) -> Self {
+        TemplateList {
+            entries: self.entries.clone(),
+            effect: self.effect.clone(),
+        }
+    }
+}
+
+impl<K, O> TemplateList<K, O> {
+    fn new() -> Self {
+        TemplateList {
+            entries: Rc::new(RefCell::new(Vec::new())),
+            effect: Rc::new(RefCell::new(None)),
+        }
+    }
+
+    fn retain_effect(&self, handle: EffectHandle) {
+        *self.effect.borrow_mut() = Some(handle);
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+
+    pub fn with<R>(&self, f: impl FnOnce(&[(K, O)]) -> R) -> R {
+        f(&self.entries.borrow())
+    }
+}
+
+impl<K: Eq + Hash + Clone, O> TemplateList<K, O> {
+    fn reconcile<T>(
+        &self,
+        items: &[T],
+        key_fn: &dyn Fn(&T) -> K,
+        view_fn: &dyn Fn(&T) -> O,
+    ) {
+        let mut entries = self.entries.borrow_mut();
+        let mut old: HashMap<K, O> = entries.drain(..).collect();
+
+        let mut reconciled = Vec::with_capacity(items.len());
+        for item in items {
+            let key = key_fn(item);
+            let output = match old.remove(&key) {
+                Some(existing) => existing,
+                None => view_fn(item),
+            };
+            reconciled.push((key, output));
+        }
+        *entries = reconciled;
+        // Any keys left in `old` belonged to removed items and are dropped here.
+    }
+}
+
+/// Maps an iterable source into a [`TemplateList`] with stable, key-based
+/// diffing: re-running this template (e.g. because its source `Signal`
+/// changed) reuses the `Output` of unchanged keys, creates children for added
+/// keys, and drops children for removed keys, preserving the source order.
+pub struct ForEach<T, K, V: Template> {
+    iterable: Signal<Vec<T>>,
+    key_fn: Rc<dyn Fn(&T) -> K>,
+    view_fn: Rc<dyn Fn(&T) -> V>,
+}
+
+impl<T, K, V> ForEach<T, K, V>
+where
+    V: Template,
+{
+    pub fn new(
+        iterable: Signal<Vec<T>>,
+        key_fn: impl Fn(&T) -> K + 'static,
+        view_fn: impl Fn(&T) -> V + 'static,
+    ) -> Self {
+        ForEach {
+            iterable,
+            key_fn: Rc::new(key_fn),
+            view_fn: Rc::new(view_fn),
+        }
+    }
+}
+
+impl<T, K, V> Template for ForEach<T, K, V>
+where
+    T: Clone + 'static,
+    K: Eq + Hash + Clone + 'static,
+    V: Template + 'static,
+    V::Output: 'static,
+{
+    type Output = TemplateList<K, V::Output>;
+
+    fn define(self) -> Self::Output {
+        let list = TemplateList::new();
+        // Shares `entries` but not the `effect` cell, so the effect closure
+        // never holds its own handle.
+        let effect_list = TemplateList {
+            entries: list.entries.clone(),
+            effect: Rc::new(RefCell::new(None)),
+        };
+        let iterable = self.iterable;
+        let key_fn = self.key_fn;
+        let view_fn = self.view_fn;
+
+        let handle = create_effect(move || {
+            let items = iterable.get();
+            effect_list.reconcile(&items, &*key_fn, &|item: &T| view_fn(item).define());
+        });
+        list.retain_effect(handle);
+
+        list
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct Counter(i32);
+
+    impl Template for Counter {
+        type Output = i32;
+
+        fn define(self) -> i32 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn reconciles_additions_removals_and_order() {
+        let source = Signal::new(vec![1, 2, 3]);
+        let builds = Rc::new(Cell::new(0));
+
+        let builds_in_view = builds.clone();
+        let for_each = ForEach::new(
+            source.clone(),
+            |n: &i32| *n,
+            move |n: &i32| {
+                builds_in_view.set(builds_in_view.get() + 1);
+                Counter(*n * 10)
+            },
+        );
+        let list = for_each.define();
+
+        assert_eq!(builds.get(), 3);
+        list.with(|entries| {
+            assert_eq!(
+                entries.iter().map(|(k, _)| *k).collect::<Vec<_>>(),
+                vec![1, 2, 3]
+            );
+        });
+
+        source.set(vec![2, 3, 4]);
+
+        // Keys 2 and 3 are reused; only the new key 4 triggers a build.
+        assert_eq!(builds.get(), 4);
+        list.with(|entries| {
+            assert_eq!(
+                entries
+                    .iter()
+                    .map(|(k, o)| (*k, *o))
+                    .collect::<Vec<_>>(),
+                vec![(2, 20), (3, 30), (4, 40)]
+            );
+        });
+    }
+
+    #[test]
+    fn dropping_the_list_drops_its_effect_and_captured_source() {
+        let source = Signal::new(vec![1]);
+        let builds = Rc::new(Cell::new(0));
+
+        let builds_in_view = builds.clone();
+        let list = ForEach::new(source.clone(), |n: &i32| *n, move |n: &i32| {
+            builds_in_view.set(builds_in_view.get() + 1);
+            Counter(*n)
+        })
+        .define();
+        assert_eq!(builds.get(), 1);
+
+        drop(list);
+
+        // No subscriber is left alive; this must not panic or resurrect a
+        // dead effect, and must not leak the effect's captured `source`
+        // clone via an `Rc` cycle through `source`'s own subscriber list.
+        source.set(vec![1, 2]);
+
+        // A still-live effect would have reconciled and built key 2.
+        assert_eq!(builds.get(), 1);
+    }
+}
@@ -0,0 +1,22 @@
+mod template;
+pub use template::*;
+
+mod signal;
+pub use signal::*;
+
+mod for_each;
+pub use for_each::*;
+
+mod block;
+pub use block::*;
+
+mod render;
+pub use render::*;
+
+mod context;
+pub use context::*;
+
+// Requires nightly features and an external `prelude` proc-macro crate this
+// tree doesn't include; see the `legacy-examples` feature in Cargo.toml.
+#[cfg(all(test, feature = "legacy-examples"))]
+mod tests;
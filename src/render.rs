@@ -0,0 +1,72 @@
+use std::fmt;
+
+/// Sink a [`RenderTo`] template writes into incrementally, instead of
+/// materializing a full `Output` value up front. Any `fmt::Write` (a
+/// `String`, a `fmt::Formatter`, ...) is an `Output` for free; a structured
+/// or styled sink (e.g. a terminal formatter) implements it directly to
+/// hook into [`Output::with_span`].
+pub trait Output {
+    fn write_str(&mut self, s: &str) -> fmt::Result;
+
+    /// Wrap the writes `f` performs in a named style/attribute span (bold,
+    /// a terminal color, ...). The blanket `fmt::Write` impl below ignores
+    /// spans, since plain text has nowhere to put them.
+    fn with_span(&mut self, name: &str, f: impl FnOnce(&mut Self) -> fmt::Result) -> fmt::Result
+    where
+        Self: Sized,
+    {
+        let _ = name;
+        f(self)
+    }
+}
+
+impl<W: fmt::Write> Output for W {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        fmt::Write::write_str(self, s)
+    }
+}
+
+/// Alternative to [`crate::Template`] for templates whose product is text or
+/// a byte stream: emits incrementally into an [`Output`] sink rather than
+/// materializing the whole `Output` value, avoiding the intermediate
+/// allocation for large outputs.
+///
+/// A `#[template]`-derived `render_to` would just walk a struct's fields and
+/// call `render_to` on each in turn -- mechanical boilerplate a proc macro
+/// (out of scope here; see the note on `BasePage` in `src/block.rs` for why)
+/// saves you from writing per type, but not a new capability over what's
+/// written here by hand for the test below.
+pub trait RenderTo {
+    fn render_to(&self, out: &mut impl Output) -> fmt::Result;
+
+    /// Convenience for the common case of rendering to an owned `String`.
+    fn render(&self) -> String {
+        let mut buf = String::new();
+        self.render_to(&mut buf)
+            .expect("String is an infallible Output sink");
+        buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Greeting<'a> {
+        name: &'a str,
+    }
+
+    impl RenderTo for Greeting<'_> {
+        fn render_to(&self, out: &mut impl Output) -> fmt::Result {
+            out.write_str("Hello, ")?;
+            out.with_span("name", |out| out.write_str(self.name))?;
+            out.write_str("!")
+        }
+    }
+
+    #[test]
+    fn renders_to_string() {
+        let greeting = Greeting { name: "world" };
+        assert_eq!(greeting.render(), "Hello, world!");
+    }
+}
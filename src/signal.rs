@@ -0,0 +1,234 @@
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+/// Strong handle to a running [`create_effect`] body. The effect keeps
+/// re-running on its dependencies' `set` calls for as long as (and only as
+/// long as) this handle, or a clone of it, is kept alive; callers are
+/// expected to store it somewhere with the lifetime they want the effect to
+/// have (e.g. alongside the `Output` it populates). Dropping every clone
+/// drops the effect's closure and everything it captured.
+pub type EffectHandle = Rc<dyn Fn()>;
+
+type WeakListener = Weak<dyn Fn()>;
+
+thread_local! {
+    static LISTENER_STACK: RefCell<Vec<WeakListener>> = RefCell::new(Vec::new());
+}
+
+/// Runs `listener` with it pushed onto the thread-local listener stack (so a
+/// [`Signal::get`] during the call can subscribe it), popping it afterwards
+/// even if `listener` panics.
+fn invoke(listener: &EffectHandle) {
+    LISTENER_STACK.with(|stack| stack.borrow_mut().push(Rc::downgrade(listener)));
+
+    struct PopGuard;
+    impl Drop for PopGuard {
+        fn drop(&mut self) {
+            LISTENER_STACK.with(|stack| {
+                stack.borrow_mut().pop();
+            });
+        }
+    }
+    let _guard = PopGuard;
+
+    listener();
+}
+
+/// Reactive cell. Reading through [`Signal::get`] (or [`Signal::with`]) inside a
+/// [`create_effect`] body subscribes that effect; writing through [`Signal::set`]
+/// re-runs every subscriber that is still alive.
+///
+/// Subscribers are held as [`Weak`] references: a `Signal` does not keep an
+/// effect that reads it alive by itself (callers typically capture the very
+/// `Signal` they're subscribing into the effect body, e.g. [`ForEach`](crate::ForEach),
+/// which would otherwise be an `Rc` cycle that never drops).
+pub struct Signal<T> {
+    value: Rc<RefCell<T>>,
+    subscribers: Rc<RefCell<Vec<WeakListener>>>,
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        Signal {
+            value: self.value.clone(),
+            subscribers: self.subscribers.clone(),
+        }
+    }
+}
+
+impl<T> Signal<T> {
+    pub fn new(value: T) -> Self {
+        Signal {
+            value: Rc::new(RefCell::new(value)),
+            subscribers: Rc::new(RefCell::new(Vec::new())),
+        }
+    }
+
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.track();
+        self.value.borrow().clone()
+    }
+
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.track();
+        f(&self.value.borrow())
+    }
+
+    pub fn set(&self, value: T) {
+        *self.value.borrow_mut() = value;
+        self.notify();
+    }
+
+    fn track(&self) {
+        LISTENER_STACK.with(|stack| {
+            if let Some(listener) = stack.borrow().last() {
+                let mut subscribers = self.subscribers.borrow_mut();
+                if !subscribers.iter().any(|s| s.ptr_eq(listener)) {
+                    subscribers.push(listener.clone());
+                }
+            }
+        });
+    }
+
+    fn notify(&self) {
+        let subscribers = self.subscribers.borrow().clone();
+        for weak in &subscribers {
+            if let Some(listener) = weak.upgrade() {
+                invoke(&listener);
+            }
+        }
+        // Drop subscriptions for effects that no one kept alive.
+        self.subscribers.borrow_mut().retain(|s| s.upgrade().is_some());
+    }
+}
+
+/// Runs `f` immediately and re-runs it whenever a [`Signal`] it read via
+/// `get`/`with` during any of its runs is subsequently `set` — for as long as
+/// the returned [`EffectHandle`] (or a clone of it) stays alive. A
+/// subscription is not dropped when a later run stops reading that signal.
+/// Re-entrant
+/// writes (an effect setting a signal it also reads) are guarded against so
+/// the effect cannot recurse into itself.
+pub fn create_effect(f: impl Fn() + 'static) -> EffectHandle {
+    let running = Rc::new(Cell::new(false));
+    let listener: EffectHandle = Rc::new(move || {
+        if running.get() {
+            return;
+        }
+        running.set(true);
+        f();
+        running.set(false);
+    });
+
+    invoke(&listener);
+
+    listener
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effect_reruns_on_set() {
+        let signal = Signal::new(1);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let signal_in_effect = signal.clone();
+        let seen_in_effect = seen.clone();
+        let _effect = create_effect(move || {
+            seen_in_effect.borrow_mut().push(signal_in_effect.get());
+        });
+
+        signal.set(2);
+        signal.set(3);
+
+        assert_eq!(*seen.borrow(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn resubscribing_does_not_duplicate_subscribers() {
+        let signal = Signal::new(0);
+        let runs = Rc::new(Cell::new(0));
+
+        let signal_in_effect = signal.clone();
+        let runs_in_effect = runs.clone();
+        let _effect = create_effect(move || {
+            runs_in_effect.set(runs_in_effect.get() + 1);
+            let _ = signal_in_effect.get();
+            let _ = signal_in_effect.get();
+        });
+
+        signal.set(1);
+
+        assert_eq!(runs.get(), 2);
+    }
+
+    #[test]
+    fn self_write_does_not_recurse() {
+        let signal = Signal::new(0);
+        let runs = Rc::new(Cell::new(0));
+
+        let signal_in_effect = signal.clone();
+        let runs_in_effect = runs.clone();
+        let _effect = create_effect(move || {
+            runs_in_effect.set(runs_in_effect.get() + 1);
+            let current = signal_in_effect.get();
+            if current == 0 {
+                signal_in_effect.set(1);
+            }
+        });
+
+        assert_eq!(runs.get(), 1);
+    }
+
+    #[test]
+    fn dropping_the_effect_handle_drops_its_captures_even_when_it_owns_its_own_trigger_signal() {
+        struct DropMarker(Rc<Cell<bool>>);
+        impl Drop for DropMarker {
+            fn drop(&mut self) {
+                self.0.set(true);
+            }
+        }
+
+        let dropped = Rc::new(Cell::new(false));
+        let signal = Signal::new(0);
+
+        {
+            let marker = DropMarker(dropped.clone());
+            let signal_in_effect = signal.clone();
+            let _effect = create_effect(move || {
+                let _ = signal_in_effect.get();
+                let _ = &marker;
+            });
+            // `_effect` (and the signal + marker it captured) drop here.
+        }
+
+        assert!(dropped.get());
+
+        // The signal itself is still usable; it just has no live subscribers.
+        signal.set(1);
+    }
+
+    #[test]
+    fn panicking_effect_does_not_leave_a_dangling_listener_on_the_stack() {
+        let panicking = Signal::new(0);
+        let signal_in_effect = panicking.clone();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            create_effect(move || {
+                let _ = signal_in_effect.get();
+                panic!("effect body panics");
+            })
+        }));
+        assert!(result.is_err());
+
+        // Without the RAII pop guard, the panicking invoke() would have
+        // left its entry on LISTENER_STACK; any later, unrelated
+        // Signal::get() would then spuriously subscribe that dead listener.
+        LISTENER_STACK.with(|stack| assert!(stack.borrow().is_empty()));
+    }
+}
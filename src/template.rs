@@ -1,11 +1,51 @@
 pub trait Template {
     type Output;
 
+    /// Contract for a derived `#[block]` struct (see [`crate::Block`]): the
+    /// generated `define` must call the base's `define` first, producing the
+    /// base's default [`crate::Block`] slots, and only then substitute each
+    /// overridden block via [`crate::Block::override_with`] before
+    /// returning -- see `src/block.rs`'s hand-written `BasePage`/
+    /// `DerivedPage` for the order this requires. (No macro generates this
+    /// in-tree; see the note on `TemplateConstruction::create` below.)
     fn define(self) -> <Self as Template>::Output;
 }
 
 pub trait TemplateConstruction: /*FnOnce() + */ Default + Template {
-    fn on_create(&mut self, f: impl FnOnce(&mut Self::Output) + 'static);
+    /// Contract for implementations that want `f` to react to state it
+    /// reads: run `f` inside a [`crate::create_effect`], so any
+    /// [`crate::Signal::get`] it performs subscribes it and a later matching
+    /// `set` re-runs `f` against the same `Output` instead of rebuilding it.
+    /// Because that rerun can happen long after the `build`/`create` call
+    /// that registered `f` returned, such an implementation should
+    /// [`crate::capture_context`] at registration time and replay `f`
+    /// through [`crate::ContextSnapshot::with`] on every (re)run, rather
+    /// than relying on whatever `ContextScope` happens to be active when the
+    /// rerun actually fires. (No implementation of this trait exists in
+    /// this crate yet -- it's generated by the `#[template]` proc macro,
+    /// which lives outside this tree.)
+    fn on_create(&mut self, f: impl Fn(&mut Self::Output) + 'static);
+
+    /// Contract for a derived `#[block]` struct: the generated `create` must
+    /// run the base's `on_create`-driven construction first -- i.e. call the
+    /// base's own `create`, so its effects register exactly as they would
+    /// for the base alone -- and only then substitute each overridden block
+    /// via [`crate::Block::override_with`] into the `Output` it returns.
+    /// Resolving overrides before the base's construction runs would let a
+    /// derived block's value be clobbered by the base's own default-block
+    /// write inside its `on_create`. (No implementation of this trait, nor
+    /// of the `#[block]` substitution it describes, exists in this crate
+    /// yet; `src/block.rs`'s `BasePage`/`DerivedPage` demonstrates the same
+    /// ordering by hand through `Template::define` instead, since neither
+    /// has an `on_create` to run.)
     fn create(self) -> Self::Output;
+
+    /// Contract for implementations: establish a fresh [`crate::ContextScope`]
+    /// around the construction of `Self::Output` and `f`'s call, so `f` (and
+    /// anything `create`/`define` calls transitively) may read context
+    /// published higher up the tree via [`crate::use_context`] without it
+    /// being threaded through explicitly. (No implementation of this trait
+    /// exists in this crate yet -- it's generated by the `#[template]` proc
+    /// macro, which lives outside this tree.)
     fn build<O>(self, f: impl FnOnce(Self::Output) -> O) -> O;
 }
\ No newline at end of file